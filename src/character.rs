@@ -0,0 +1,79 @@
+use crate::document::Document;
+
+/// The fields that make up a character sheet, in the order they are
+/// navigated with `j`/`k`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CharacterField {
+    Name,
+    Class,
+    Notes,
+}
+
+impl CharacterField {
+    pub const ALL: [CharacterField; 3] = [
+        CharacterField::Name,
+        CharacterField::Class,
+        CharacterField::Notes,
+    ];
+
+    /// The field after this one, wrapping around to the first.
+    pub fn next(self) -> CharacterField {
+        let index = Self::ALL.iter().position(|&f| f == self).unwrap();
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// The field before this one, wrapping around to the last.
+    pub fn prev(self) -> CharacterField {
+        let index = Self::ALL.iter().position(|&f| f == self).unwrap();
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CharacterField::Name => "Name",
+            CharacterField::Class => "Class",
+            CharacterField::Notes => "Notes",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Character {
+    pub name: String,
+    pub class: String,
+    pub notes: Document,
+}
+
+impl Character {
+    pub fn new() -> Character {
+        Character {
+            name: String::new(),
+            class: String::new(),
+            notes: Document::new(),
+        }
+    }
+
+    /// The plain-text fields, i.e. every field but `Notes`, which is backed
+    /// by a `Document` instead of a `String`.
+    pub fn field(&self, field: CharacterField) -> &str {
+        match field {
+            CharacterField::Name => &self.name,
+            CharacterField::Class => &self.class,
+            CharacterField::Notes => panic!("Notes is backed by a Document, not a String"),
+        }
+    }
+
+    pub fn field_mut(&mut self, field: CharacterField) -> &mut String {
+        match field {
+            CharacterField::Name => &mut self.name,
+            CharacterField::Class => &mut self.class,
+            CharacterField::Notes => panic!("Notes is backed by a Document, not a String"),
+        }
+    }
+}
+
+impl Default for Character {
+    fn default() -> Character {
+        Character::new()
+    }
+}
@@ -0,0 +1,179 @@
+/// A single line of a `Document`.
+#[derive(Clone, Debug, Default)]
+pub struct Row {
+    content: String,
+}
+
+impl Row {
+    pub fn len(&self) -> usize {
+        self.content.chars().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+
+    /// The slice of this row visible between the column range `[start, end)`,
+    /// for horizontal scrolling.
+    pub fn render(&self, start: usize, end: usize) -> String {
+        if self.is_empty() {
+            return String::new();
+        }
+        let end = end.min(self.len());
+        let start = start.min(end);
+        self.content.chars().skip(start).take(end - start).collect()
+    }
+
+    fn insert(&mut self, at: usize, c: char) {
+        if at >= self.len() {
+            self.content.push(c);
+        } else {
+            let byte_at = self.byte_index(at);
+            self.content.insert(byte_at, c);
+        }
+    }
+
+    fn remove(&mut self, at: usize) {
+        if at < self.len() {
+            let byte_at = self.byte_index(at);
+            let mut rest = self.content.split_off(byte_at);
+            rest.remove(0);
+            self.content.push_str(&rest);
+        }
+    }
+
+    fn split_off(&mut self, at: usize) -> Row {
+        let byte_at = self.byte_index(at);
+        Row {
+            content: self.content.split_off(byte_at),
+        }
+    }
+
+    fn append(&mut self, other: &Row) {
+        self.content.push_str(&other.content);
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.content
+            .char_indices()
+            .nth(char_index)
+            .map(|(byte_index, _)| byte_index)
+            .unwrap_or(self.content.len())
+    }
+}
+
+impl From<&str> for Row {
+    fn from(s: &str) -> Row {
+        Row {
+            content: s.to_owned(),
+        }
+    }
+}
+
+/// A free-form multi-line text buffer, modeled on the hecto editor's
+/// document/row split: one `Row` per line, addressed by a `(row, column)`
+/// cursor.
+#[derive(Clone, Debug, Default)]
+pub struct Document {
+    rows: Vec<Row>,
+}
+
+impl Document {
+    pub fn new() -> Document {
+        Document {
+            rows: vec![Row::default()],
+        }
+    }
+
+    pub fn rows(&self) -> &[Row] {
+        &self.rows
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn row(&self, index: usize) -> Option<&Row> {
+        self.rows.get(index)
+    }
+
+    /// Insert `c` at `(row, column)`, clamped so the cursor never moves past
+    /// the end of a row or past the last row.
+    pub fn insert(&mut self, row: usize, column: usize, c: char) {
+        let row = row.min(self.rows.len() - 1);
+        self.rows[row].insert(column, c);
+    }
+
+    /// Split the row at `(row, column)` into two, moving everything from
+    /// `column` onward into a new row directly below it.
+    pub fn split_row(&mut self, row: usize, column: usize) {
+        let row = row.min(self.rows.len() - 1);
+        let new_row = self.rows[row].split_off(column);
+        self.rows.insert(row + 1, new_row);
+    }
+
+    /// Remove the character before `(row, column)`. At column 0, joins the
+    /// row with the previous one instead of removing anything from it.
+    pub fn backspace(&mut self, row: usize, column: usize) {
+        let row = row.min(self.rows.len() - 1);
+        if column == 0 {
+            if row > 0 {
+                let current = self.rows.remove(row);
+                self.rows[row - 1].append(&current);
+            }
+        } else {
+            self.rows[row].remove(column - 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn row_insert_and_remove_handle_multi_byte_chars() {
+        let mut row = Row::from("caf");
+        row.insert(3, 'é');
+        assert_eq!(row.render(0, row.len()), "café");
+        assert_eq!(row.len(), 4, "len counts chars, not bytes");
+        row.remove(3);
+        assert_eq!(row.render(0, row.len()), "caf");
+    }
+
+    #[test]
+    fn row_render_clamps_to_its_length() {
+        let row = Row::from("hello");
+        assert_eq!(row.render(0, 100), "hello");
+        assert_eq!(row.render(2, 4), "ll");
+        assert_eq!(row.render(10, 20), "");
+    }
+
+    #[test]
+    fn document_insert_and_split_row() {
+        let mut doc = Document::new();
+        doc.insert(0, 0, 'h');
+        doc.insert(0, 1, 'i');
+        assert_eq!(doc.row(0).unwrap().render(0, 2), "hi");
+
+        doc.split_row(0, 1);
+        assert_eq!(doc.len(), 2);
+        assert_eq!(doc.row(0).unwrap().render(0, 1), "h");
+        assert_eq!(doc.row(1).unwrap().render(0, 1), "i");
+    }
+
+    #[test]
+    fn document_backspace_joins_rows_at_column_zero() {
+        let mut doc = Document::new();
+        doc.insert(0, 0, 'h');
+        doc.insert(0, 1, 'i');
+        doc.split_row(0, 1);
+
+        doc.backspace(1, 0);
+        assert_eq!(doc.len(), 1);
+        assert_eq!(
+            doc.row(0).unwrap().render(0, doc.row(0).unwrap().len()),
+            "hi"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crossterm::event::KeyCode;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// A named action a screen can react to, independent of which physical key
+/// triggered it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    Select,
+    Edit,
+    Back,
+    EnterCommand,
+    Quit,
+}
+
+/// Maps physical keys to `Action`s, so navigation logic never matches a raw
+/// `KeyCode` directly. Several keys can map to the same action (e.g. `k`
+/// and the up arrow both resolve to `MoveUp`).
+pub struct Keymap {
+    bindings: HashMap<KeyCode, Action>,
+}
+
+impl Keymap {
+    pub fn resolve(&self, code: KeyCode) -> Option<Action> {
+        self.bindings.get(&code).copied()
+    }
+
+    fn vim_defaults() -> Keymap {
+        let mut bindings = HashMap::new();
+        bindings.insert(KeyCode::Char('k'), Action::MoveUp);
+        bindings.insert(KeyCode::Up, Action::MoveUp);
+        bindings.insert(KeyCode::Char('j'), Action::MoveDown);
+        bindings.insert(KeyCode::Down, Action::MoveDown);
+        bindings.insert(KeyCode::Enter, Action::Select);
+        bindings.insert(KeyCode::Char('i'), Action::Edit);
+        bindings.insert(KeyCode::Esc, Action::Back);
+        bindings.insert(KeyCode::Char(':'), Action::EnterCommand);
+        bindings.insert(KeyCode::Char('q'), Action::Quit);
+        Keymap { bindings }
+    }
+
+    /// Load the user's keymap from `config_path()`, overlaying any bindings
+    /// it specifies onto the Vim defaults. Falls back to the defaults
+    /// outright if the file is missing or unreadable.
+    pub fn load() -> Keymap {
+        match Self::config_path().and_then(|path| std::fs::read_to_string(path).ok()) {
+            Some(contents) => Keymap::parse(&contents),
+            None => Keymap::vim_defaults(),
+        }
+    }
+
+    /// `$TUI_AM_I_KEYMAP` if set, else `$HOME/.config/tui-am-i/keymap.conf`.
+    fn config_path() -> Option<PathBuf> {
+        if let Ok(path) = std::env::var("TUI_AM_I_KEYMAP") {
+            return Some(PathBuf::from(path));
+        }
+        let home = std::env::var("HOME").ok()?;
+        Some(PathBuf::from(home).join(".config/tui-am-i/keymap.conf"))
+    }
+
+    /// Parse `key = action` lines (blank lines and `#` comments ignored)
+    /// onto the Vim defaults, e.g.:
+    /// ```text
+    /// q = Back
+    /// enter = EnterCommand
+    /// ```
+    /// `key` is a named special key (see `parse_key`) or a single bare
+    /// character -- there's no modifier syntax (no `ctrl+`/`alt+`/`shift+`)
+    /// yet. Unrecognised keys, actions, or lines are skipped rather than
+    /// rejecting the whole file, so a typo only loses one binding.
+    fn parse(contents: &str) -> Keymap {
+        let mut keymap = Keymap::vim_defaults();
+        for line in contents.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let Some((key, action)) = line.split_once('=') else {
+                continue;
+            };
+            let (Some(key), Some(action)) = (parse_key(key.trim()), parse_action(action.trim()))
+            else {
+                continue;
+            };
+            keymap.bindings.insert(key, action);
+        }
+        keymap
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Keymap {
+        Keymap::vim_defaults()
+    }
+}
+
+/// Parse the `key` half of the `key = action` syntax `Keymap::parse` reads:
+/// named special keys (`up`, `enter`, ...), or a single character bound literally.
+fn parse_key(s: &str) -> Option<KeyCode> {
+    match s.to_ascii_lowercase().as_str() {
+        "up" => Some(KeyCode::Up),
+        "down" => Some(KeyCode::Down),
+        "left" => Some(KeyCode::Left),
+        "right" => Some(KeyCode::Right),
+        "enter" => Some(KeyCode::Enter),
+        "esc" | "escape" => Some(KeyCode::Esc),
+        "tab" => Some(KeyCode::Tab),
+        "backspace" => Some(KeyCode::Backspace),
+        _ if s.chars().count() == 1 => s.chars().next().map(KeyCode::Char),
+        _ => None,
+    }
+}
+
+fn parse_action(s: &str) -> Option<Action> {
+    match s {
+        "MoveUp" => Some(Action::MoveUp),
+        "MoveDown" => Some(Action::MoveDown),
+        "Select" => Some(Action::Select),
+        "Edit" => Some(Action::Edit),
+        "Back" => Some(Action::Back),
+        "EnterCommand" => Some(Action::EnterCommand),
+        "Quit" => Some(Action::Quit),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vim_defaults_resolve_j_and_k() {
+        let keymap = Keymap::vim_defaults();
+        assert_eq!(keymap.resolve(KeyCode::Char('j')), Some(Action::MoveDown));
+        assert_eq!(keymap.resolve(KeyCode::Char('k')), Some(Action::MoveUp));
+        assert_eq!(keymap.resolve(KeyCode::Down), Some(Action::MoveDown));
+        assert_eq!(keymap.resolve(KeyCode::Char('x')), None);
+    }
+
+    #[test]
+    fn parse_overlays_defaults_without_dropping_them() {
+        let keymap = Keymap::parse("q = Back\n# a comment\n\nbogus line\nx = NotAnAction\n");
+        // Overridden.
+        assert_eq!(keymap.resolve(KeyCode::Char('q')), Some(Action::Back));
+        // Untouched defaults survive.
+        assert_eq!(keymap.resolve(KeyCode::Char('j')), Some(Action::MoveDown));
+        // Invalid lines are skipped, not bound.
+        assert_eq!(keymap.resolve(KeyCode::Char('x')), None);
+    }
+
+    #[test]
+    fn parse_understands_named_keys() {
+        let keymap = Keymap::parse("enter = Back");
+        assert_eq!(keymap.resolve(KeyCode::Enter), Some(Action::Back));
+    }
+}
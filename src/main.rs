@@ -0,0 +1,19 @@
+mod character;
+mod document;
+mod keymap;
+mod prompt;
+mod state;
+mod terminal;
+mod widgets;
+
+use anyhow::Result;
+use state::Screen;
+
+fn main() -> Result<()> {
+    terminal::install_panic_hook();
+
+    let mut screen = Screen::new(Vec::new())?;
+    screen.display_screen()?;
+    screen.handle_input()?;
+    Ok(())
+}
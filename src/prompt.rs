@@ -0,0 +1,129 @@
+/// The command issued once a `Prompt` line is submitted with `Enter`.
+pub enum Command {
+    Write,
+    Quit,
+    WriteQuit,
+    New,
+    /// Anything we don't recognise; reported back so the caller can decide
+    /// whether to surface an error.
+    Unknown(String),
+}
+
+/// A single-line command prompt, rendered at the bottom of the screen,
+/// in the spirit of the Helix/Vim `:` prompt.
+pub struct Prompt {
+    pub leader: char,
+    pub line: String,
+    pub cursor: usize,
+}
+
+impl Prompt {
+    pub fn new(leader: char) -> Prompt {
+        Prompt {
+            leader,
+            line: String::new(),
+            cursor: 0,
+        }
+    }
+
+    pub fn insert(&mut self, c: char) {
+        self.line.insert(self.cursor, c);
+        self.cursor += c.len_utf8();
+    }
+
+    pub fn backspace(&mut self) {
+        if self.cursor > 0 {
+            let prev = self.line[..self.cursor]
+                .chars()
+                .next_back()
+                .expect("cursor is past the start of the line");
+            self.cursor -= prev.len_utf8();
+            self.line.remove(self.cursor);
+        }
+    }
+
+    pub fn move_left(&mut self) {
+        if self.cursor > 0 {
+            let prev = self.line[..self.cursor]
+                .chars()
+                .next_back()
+                .expect("cursor is past the start of the line");
+            self.cursor -= prev.len_utf8();
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if self.cursor < self.line.len() {
+            let next = self.line[self.cursor..]
+                .chars()
+                .next()
+                .expect("cursor is before the end of the line");
+            self.cursor += next.len_utf8();
+        }
+    }
+
+    /// Render the prompt's display text, e.g. `:wq`.
+    pub fn display(&self) -> String {
+        format!("{}{}", self.leader, self.line)
+    }
+
+    /// Parse the current line into a `Command`, consuming the prompt.
+    pub fn into_command(self) -> Command {
+        match self.line.as_str() {
+            "w" => Command::Write,
+            "q" => Command::Quit,
+            "wq" => Command::WriteQuit,
+            "new" => Command::New,
+            _ => Command::Unknown(self.line),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(line: &str) -> Prompt {
+        let mut prompt = Prompt::new(':');
+        for c in line.chars() {
+            prompt.insert(c);
+        }
+        prompt
+    }
+
+    #[test]
+    fn recognised_commands_parse() {
+        assert!(matches!(typed("w").into_command(), Command::Write));
+        assert!(matches!(typed("q").into_command(), Command::Quit));
+        assert!(matches!(typed("wq").into_command(), Command::WriteQuit));
+        assert!(matches!(typed("new").into_command(), Command::New));
+    }
+
+    #[test]
+    fn unrecognised_command_is_reported_back() {
+        match typed("bogus").into_command() {
+            Command::Unknown(line) => assert_eq!(line, "bogus"),
+            _ => panic!("expected Command::Unknown"),
+        }
+    }
+
+    #[test]
+    fn backspace_edits_the_line() {
+        let mut prompt = typed("wx");
+        prompt.backspace();
+        prompt.insert('q');
+        assert_eq!(prompt.line, "wq");
+        assert_eq!(prompt.display(), ":wq");
+    }
+
+    #[test]
+    fn arrows_move_the_cursor_without_changing_the_line() {
+        let mut prompt = typed("wq");
+        assert_eq!(prompt.cursor, 2);
+        prompt.move_left();
+        assert_eq!(prompt.cursor, 1);
+        prompt.move_right();
+        assert_eq!(prompt.cursor, 2);
+        assert_eq!(prompt.line, "wq");
+    }
+}
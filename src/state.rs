@@ -1,5 +1,9 @@
-use crate::character::Character;
-use anyhow::{Context, Result};
+use crate::character::{Character, CharacterField};
+use crate::keymap::{Action, Keymap};
+use crate::prompt::{Command, Prompt};
+use crate::terminal::TerminalSession;
+use crate::widgets::TextInput;
+use anyhow::Result;
 use crossterm::{
     cursor,
     event::{read, Event, KeyCode, KeyEvent},
@@ -9,12 +13,17 @@ use crossterm::{
 use std::io::{stdout, Stdout, Write};
 use tui::{
     backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     text::{Span, Spans},
     widgets::{Block, Borders, Paragraph},
     Terminal,
 };
 
+/// The fields rendered as single-line entries at the top of the sheet;
+/// `Notes` gets its own scrolling block below them.
+const TEXT_FIELDS: [CharacterField; 2] = [CharacterField::Name, CharacterField::Class];
+
 enum HandleKeyboardInput {
     ChangeState(Box<dyn State>),
     Input,
@@ -24,20 +33,21 @@ enum HandleKeyboardInput {
 pub struct Screen {
     state: Option<Box<dyn State>>,
     stdout: Stdout,
+    // Held only for its `Drop` impl, which restores the terminal.
+    _session: TerminalSession,
 }
 
 impl Screen {
-    pub fn new(saved_characters: Vec<Character>) -> Screen {
-        Screen {
-            state: Some(Box::new(SelectScreen {
-                saved_characters: saved_characters.clone(),
-            })),
+    pub fn new(saved_characters: Vec<Character>) -> Result<Screen> {
+        Ok(Screen {
+            state: Some(Box::new(SelectScreen::new(saved_characters))),
             stdout: stdout(),
-        }
+            _session: TerminalSession::enter()?,
+        })
     }
 
     pub fn display_screen(&mut self) -> Result<()> {
-        if let Some(state) = &self.state {
+        if let Some(state) = &mut self.state {
             state.display_screen(&mut self.stdout)?;
         }
         Ok(())
@@ -48,35 +58,122 @@ impl Screen {
             self.stdout.flush()?;
             match read()? {
                 Event::Key(event) => {
-                    if let Some(state) = &self.state {
-                        match state.handle_keyboard_event(&mut self.stdout, event)? {
-                            None => {}
-                            _ => {}
+                    let outcome = match &mut self.state {
+                        Some(state) => Some(state.handle_keyboard_event(&mut self.stdout, event)?),
+                        None => None,
+                    };
+                    match outcome {
+                        Some(HandleKeyboardInput::ChangeState(new_state)) => {
+                            self.state = Some(new_state);
+                            self.display_screen()?;
                         }
+                        Some(HandleKeyboardInput::Exit) => return Ok(()),
+                        Some(HandleKeyboardInput::Input) => self.display_screen()?,
+                        None => {}
                     }
                 }
                 _ => {}
             }
         }
-        Ok(())
     }
 }
 
 trait State {
-    fn display_screen(&self, stdout: &mut Stdout) -> Result<()>;
+    fn display_screen(&mut self, stdout: &mut Stdout) -> Result<()>;
     fn handle_keyboard_event(
-        &self,
-        stdout: &Stdout,
+        &mut self,
+        stdout: &mut Stdout,
         event: KeyEvent,
     ) -> Result<HandleKeyboardInput>;
 }
 
+/// Act on a command parsed from a `Prompt` line. `character` is the
+/// sheet to persist for `w`/`wq`, if the current screen has one. Returns
+/// the resulting `HandleKeyboardInput`, plus a status message to show the
+/// user, if any (e.g. an unrecognised command).
+fn dispatch_command(
+    command: Command,
+    character: Option<&Character>,
+) -> (HandleKeyboardInput, Option<String>) {
+    match command {
+        Command::Write => {
+            if let Some(character) = character {
+                persist_character(character);
+            }
+            (HandleKeyboardInput::Input, None)
+        }
+        Command::Quit => (HandleKeyboardInput::Exit, None),
+        Command::WriteQuit => {
+            if let Some(character) = character {
+                persist_character(character);
+            }
+            (HandleKeyboardInput::Exit, None)
+        }
+        Command::New => (
+            HandleKeyboardInput::ChangeState(Box::new(CharacterScreen::new(Character::new()))),
+            None,
+        ),
+        Command::Unknown(line) => (
+            HandleKeyboardInput::Input,
+            Some(format!("Unknown command: {}", line)),
+        ),
+    }
+}
+
+/// Write `character` to `characters/<name>.txt`, overwriting any existing
+/// file for that name. There's no save-file format to speak of yet (no
+/// serde/toml dependency is available), so this is a minimal `Debug` dump
+/// rather than anything meant to be read back in; best-effort, like
+/// `TerminalSession::restore` -- a failed save shouldn't crash the editor.
+fn persist_character(character: &Character) {
+    let _ = std::fs::create_dir_all("characters");
+    let path = format!("characters/{}.txt", sanitize_filename(&character.name));
+    let _ = std::fs::write(path, format!("{:#?}", character));
+}
+
+/// Replace anything but alphanumerics/`-`/`_` with `_`, and fall back to
+/// `unnamed` for an empty name, so a character's name can't escape the
+/// `characters/` directory or produce an empty file name.
+fn sanitize_filename(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if sanitized.is_empty() {
+        "unnamed".to_string()
+    } else {
+        sanitized
+    }
+}
+
 struct SelectScreen {
     saved_characters: Vec<Character>,
+    prompt: Option<Prompt>,
+    // Feedback from the last command dispatched from `prompt`, e.g. an
+    // unrecognised command; cleared as soon as a new prompt is opened.
+    status: Option<String>,
+    keymap: Keymap,
+}
+
+impl SelectScreen {
+    fn new(saved_characters: Vec<Character>) -> SelectScreen {
+        SelectScreen {
+            saved_characters,
+            prompt: None,
+            status: None,
+            keymap: Keymap::load(),
+        }
+    }
 }
 
 impl State for SelectScreen {
-    fn display_screen(&self, stdout: &mut Stdout) -> Result<()> {
+    fn display_screen(&mut self, stdout: &mut Stdout) -> Result<()> {
         execute!(stdout, Clear(All), cursor::MoveTo(0, 0))?;
 
         for character in &self.saved_characters {
@@ -84,118 +181,504 @@ impl State for SelectScreen {
             stdout.flush()?;
         }
 
-        write!(stdout, "New Character Sheet..")?;
+        write!(stdout, "New Character Sheet..\r\n")?;
         stdout.flush()?;
-        execute!(stdout, cursor::MoveTo(0, 0))?;
+
+        if let Some(prompt) = &self.prompt {
+            let prompt_row = cursor::position()?.1;
+            write!(stdout, "{}", prompt.display())?;
+            stdout.flush()?;
+            execute!(stdout, cursor::MoveTo(prompt.cursor as u16 + 1, prompt_row))?;
+        } else {
+            if let Some(status) = &self.status {
+                write!(stdout, "{}\r\n", status)?;
+                stdout.flush()?;
+            }
+            execute!(stdout, cursor::MoveTo(0, 0))?;
+        }
         Ok(())
     }
 
     fn handle_keyboard_event(
-        &self,
-        mut stdout: &Stdout,
+        &mut self,
+        stdout: &mut Stdout,
         event: KeyEvent,
     ) -> Result<HandleKeyboardInput> {
+        if let Some(prompt) = &mut self.prompt {
+            return Ok(match event.code {
+                KeyCode::Char(c) => {
+                    prompt.insert(c);
+                    HandleKeyboardInput::Input
+                }
+                KeyCode::Backspace => {
+                    prompt.backspace();
+                    HandleKeyboardInput::Input
+                }
+                KeyCode::Left => {
+                    prompt.move_left();
+                    HandleKeyboardInput::Input
+                }
+                KeyCode::Right => {
+                    prompt.move_right();
+                    HandleKeyboardInput::Input
+                }
+                KeyCode::Enter => {
+                    let command = self.prompt.take().unwrap().into_command();
+                    let (outcome, status) = dispatch_command(command, None);
+                    self.status = status;
+                    outcome
+                }
+                KeyCode::Esc => {
+                    self.prompt = None;
+                    HandleKeyboardInput::Input
+                }
+                _ => HandleKeyboardInput::Input,
+            });
+        }
+
         let current_row = cursor::position()?.1 as u16;
-        let all_characters_length = all_characters.len() as u16;
-        let all_characters = self.saved_characters;
+        let all_characters_length = self.saved_characters.len() as u16;
 
-        match event.code {
-            // On matching the Esc key, return false to the caller.
-            // This will end the main loop and the application.
-            KeyCode::Esc => Ok(HandleKeyboardInput::Exit),
+        match self.keymap.resolve(event.code) {
+            // `Back` has nowhere to go back to from the root screen, so it
+            // exits the application, same as `Quit`.
+            Some(Action::Back) | Some(Action::Quit) => Ok(HandleKeyboardInput::Exit),
 
-            // Currently set to "Vim" key-bindings for `up` and `down` navigation.
-            // TODO: Possible feature: user config for key-bindings.
-            KeyCode::Char('k') => {
+            Some(Action::EnterCommand) => {
+                self.prompt = Some(Prompt::new(':'));
+                self.status = None;
+                Ok(HandleKeyboardInput::Input)
+            }
+
+            Some(Action::MoveUp) => {
                 execute!(stdout, cursor::MoveToPreviousLine(1))?;
                 Ok(HandleKeyboardInput::Input)
             }
-            KeyCode::Char('j') => {
+            Some(Action::MoveDown) => {
                 if current_row != all_characters_length {
                     execute!(stdout, cursor::MoveToNextLine(1))?;
-                } else {
                 }
                 Ok(HandleKeyboardInput::Input)
             }
-            KeyCode::Enter => {
+            Some(Action::Select) => {
                 if current_row == all_characters_length {
                     Ok(HandleKeyboardInput::ChangeState(Box::new(
-                        CharacterScreen {
-                            current_character: Some(Character::new()),
-                        },
+                        CharacterScreen::new(Character::new()),
                     )))
                 } else {
-                    let selected_character = all_characters[current_row as usize];
-
-                    Ok(HandleKeyboardInput::ChangeState(Box::new(CharacterScreen {
-                        current_character: Some(selected_character),
-                    })))
+                    let selected_character = self.saved_characters[current_row as usize].clone();
+                    Ok(HandleKeyboardInput::ChangeState(Box::new(
+                        CharacterScreen::new(selected_character),
+                    )))
                 }
             }
-            _ => { Ok(HandleKeyboardInput::Input) }
+            None => Ok(HandleKeyboardInput::Input),
         }
     }
 }
 
+/// Whether `CharacterScreen` is navigating between fields or editing the
+/// currently selected one.
+enum InputMode {
+    Normal,
+    Editing,
+}
+
+/// Only alphabetic characters are accepted for a character's class, and
+/// they're normalised to upper case (e.g. a future "level" field would use
+/// a digits-only filter instead). A letter whose upper case is more than
+/// one char (e.g. 'ß') is kept as typed.
+fn class_filter(c: char) -> Option<char> {
+    if c.is_alphabetic() {
+        let mut upper = c.to_uppercase();
+        match (upper.next(), upper.next()) {
+            (Some(u), None) => Some(u),
+            _ => Some(c),
+        }
+    } else {
+        None
+    }
+}
+
 struct CharacterScreen {
-    current_character: Option<Character>,
+    current_character: Character,
+    field: CharacterField,
+    input_mode: InputMode,
+    name_input: TextInput,
+    class_input: TextInput,
+    // Cursor position within `current_character.notes` as `(row, column)`,
+    // and the index of the first row/column visible in the notes block.
+    notes_cursor: (usize, usize),
+    notes_offset: usize,
+    notes_col_offset: usize,
+    prompt: Option<Prompt>,
+    // Feedback from the last command dispatched from `prompt`, e.g. an
+    // unrecognised command; cleared as soon as a new prompt is opened.
+    status: Option<String>,
+    keymap: Keymap,
+}
+
+impl CharacterScreen {
+    fn new(current_character: Character) -> CharacterScreen {
+        CharacterScreen {
+            current_character,
+            field: CharacterField::Name,
+            input_mode: InputMode::Normal,
+            name_input: TextInput::new(),
+            class_input: TextInput::with_filter(class_filter),
+            notes_cursor: (0, 0),
+            notes_offset: 0,
+            notes_col_offset: 0,
+            prompt: None,
+            status: None,
+            keymap: Keymap::load(),
+        }
+    }
+
+    /// The widget backing a `TEXT_FIELDS` entry; `Notes` has no `TextInput`.
+    fn text_input_mut(&mut self, field: CharacterField) -> &mut TextInput {
+        match field {
+            CharacterField::Name => &mut self.name_input,
+            CharacterField::Class => &mut self.class_input,
+            CharacterField::Notes => panic!("Notes is not backed by a TextInput"),
+        }
+    }
+
+    fn text_input(&self, field: CharacterField) -> &TextInput {
+        match field {
+            CharacterField::Name => &self.name_input,
+            CharacterField::Class => &self.class_input,
+            CharacterField::Notes => panic!("Notes is not backed by a TextInput"),
+        }
+    }
+
+    /// Switch into `Editing` mode, seeding the focused widget with the
+    /// currently selected field's value.
+    fn start_editing(&mut self) {
+        if self.field != CharacterField::Notes {
+            let value = self.current_character.field(self.field).to_owned();
+            self.text_input_mut(self.field).set_value(value);
+        }
+        self.input_mode = InputMode::Editing;
+    }
+
+    /// Write the focused widget's value back into the selected field and
+    /// return to `Normal` mode.
+    fn commit_edit(&mut self) {
+        let value = self.text_input(self.field).value().to_owned();
+        *self.current_character.field_mut(self.field) = value;
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Discard the in-progress edit and return to `Normal` mode.
+    fn cancel_edit(&mut self) {
+        self.input_mode = InputMode::Normal;
+    }
+
+    fn handle_normal_key(&mut self, event: KeyEvent) -> HandleKeyboardInput {
+        match self.keymap.resolve(event.code) {
+            Some(Action::EnterCommand) => {
+                self.prompt = Some(Prompt::new(':'));
+                self.status = None;
+            }
+            Some(Action::MoveUp) => self.field = self.field.prev(),
+            Some(Action::MoveDown) => self.field = self.field.next(),
+            Some(Action::Select) | Some(Action::Edit) => self.start_editing(),
+            // Unlike `SelectScreen`, quitting here can discard edits, so
+            // `q` doesn't exit directly -- it opens the command prompt
+            // pre-filled with `q`, routing through the same confirmation
+            // (pressing Enter) as typing `:q` would.
+            Some(Action::Quit) => {
+                let mut prompt = Prompt::new(':');
+                prompt.insert('q');
+                self.prompt = Some(prompt);
+                self.status = None;
+            }
+            // `Back` has no parent screen to return to yet; swallow it.
+            Some(Action::Back) | None => {}
+        }
+        HandleKeyboardInput::Input
+    }
+
+    fn handle_editing_key(&mut self, event: KeyEvent) -> HandleKeyboardInput {
+        if self.field == CharacterField::Notes {
+            return self.handle_notes_key(event);
+        }
+        match event.code {
+            KeyCode::Enter => self.commit_edit(),
+            KeyCode::Esc => self.cancel_edit(),
+            _ => {
+                self.text_input_mut(self.field).handle_key(event);
+            }
+        }
+        HandleKeyboardInput::Input
+    }
+
+    /// Row length helper, clamped to 0 for an out-of-range row.
+    fn notes_row_len(&self, row: usize) -> usize {
+        self.current_character
+            .notes
+            .row(row)
+            .map(crate::document::Row::len)
+            .unwrap_or(0)
+    }
+
+    fn clamp_notes_column(&mut self) {
+        self.notes_cursor.1 = self
+            .notes_cursor
+            .1
+            .min(self.notes_row_len(self.notes_cursor.0));
+    }
+
+    fn handle_notes_key(&mut self, event: KeyEvent) -> HandleKeyboardInput {
+        let (row, column) = self.notes_cursor;
+        match event.code {
+            KeyCode::Char(c) => {
+                self.current_character.notes.insert(row, column, c);
+                self.notes_cursor.1 += 1;
+            }
+            KeyCode::Enter => {
+                self.current_character.notes.split_row(row, column);
+                self.notes_cursor = (row + 1, 0);
+            }
+            KeyCode::Backspace => {
+                if column == 0 && row > 0 {
+                    let prev_len = self.notes_row_len(row - 1);
+                    self.current_character.notes.backspace(row, column);
+                    self.notes_cursor = (row - 1, prev_len);
+                } else if column > 0 {
+                    self.current_character.notes.backspace(row, column);
+                    self.notes_cursor.1 -= 1;
+                }
+            }
+            KeyCode::Left => {
+                if column > 0 {
+                    self.notes_cursor.1 -= 1;
+                } else if row > 0 {
+                    self.notes_cursor = (row - 1, self.notes_row_len(row - 1));
+                }
+            }
+            KeyCode::Right => {
+                if column < self.notes_row_len(row) {
+                    self.notes_cursor.1 += 1;
+                } else if row + 1 < self.current_character.notes.len() {
+                    self.notes_cursor = (row + 1, 0);
+                }
+            }
+            KeyCode::Up if row > 0 => {
+                self.notes_cursor.0 -= 1;
+                self.clamp_notes_column();
+            }
+            KeyCode::Down if row + 1 < self.current_character.notes.len() => {
+                self.notes_cursor.0 += 1;
+                self.clamp_notes_column();
+            }
+            KeyCode::Esc => self.input_mode = InputMode::Normal,
+            _ => {}
+        }
+        HandleKeyboardInput::Input
+    }
+
+    fn handle_prompt_key(&mut self, event: KeyEvent) -> HandleKeyboardInput {
+        let prompt = self
+            .prompt
+            .as_mut()
+            .expect("handle_prompt_key requires an active prompt");
+        match event.code {
+            KeyCode::Char(c) => {
+                prompt.insert(c);
+                HandleKeyboardInput::Input
+            }
+            KeyCode::Backspace => {
+                prompt.backspace();
+                HandleKeyboardInput::Input
+            }
+            KeyCode::Left => {
+                prompt.move_left();
+                HandleKeyboardInput::Input
+            }
+            KeyCode::Right => {
+                prompt.move_right();
+                HandleKeyboardInput::Input
+            }
+            KeyCode::Enter => {
+                let command = self.prompt.take().unwrap().into_command();
+                let (outcome, status) = dispatch_command(command, Some(&self.current_character));
+                self.status = status;
+                outcome
+            }
+            KeyCode::Esc => {
+                self.prompt = None;
+                HandleKeyboardInput::Input
+            }
+            _ => HandleKeyboardInput::Input,
+        }
+    }
+
+    /// Split the terminal area into the fields block, the notes block, and
+    /// the command-prompt line, in that order.
+    fn layout(area: Rect) -> Vec<Rect> {
+        Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(TEXT_FIELDS.len() as u16 + 2),
+                Constraint::Min(0),
+                Constraint::Length(1),
+            ])
+            .split(area)
+    }
 }
 
 impl State for CharacterScreen {
-    fn display_screen(&self, stdout: &mut Stdout) -> Result<()> {
+    fn display_screen(&mut self, stdout: &mut Stdout) -> Result<()> {
         let backend = CrosstermBackend::new(stdout);
+        let mut terminal = Terminal::new(backend)?;
+        terminal.clear()?;
+
+        let notes_area = Self::layout(terminal.size()?)[1];
+        let notes_area_height = notes_area.height.saturating_sub(2) as usize;
+        let notes_area_width = notes_area.width.saturating_sub(2) as usize;
+        if self.notes_cursor.0 < self.notes_offset {
+            self.notes_offset = self.notes_cursor.0;
+        } else if notes_area_height > 0
+            && self.notes_cursor.0 >= self.notes_offset + notes_area_height
+        {
+            self.notes_offset = self.notes_cursor.0 - notes_area_height + 1;
+        }
+        if self.notes_cursor.1 < self.notes_col_offset {
+            self.notes_col_offset = self.notes_cursor.1;
+        } else if notes_area_width > 0
+            && self.notes_cursor.1 >= self.notes_col_offset + notes_area_width
+        {
+            self.notes_col_offset = self.notes_cursor.1 - notes_area_width + 1;
+        }
 
-        // This vector of vectors represents each line of our `Paragraph`,
+        // This vector of spans represents each line of the fields `Paragraph`,
         // TODO: This method will need to be reviewed; I'm not sure if this
         // is the best way to render the text to the screen.
-        let character_text = vec![
-            Spans::from(vec![
-                Span::styled("Name: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(
-                    self.current_character
-                        .as_ref()
-                        .context("No Character")?
-                        .name
-                        .as_str(),
-                ),
-            ]),
-            Spans::from(vec![
-                Span::styled("Class: ", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(
-                    self.current_character
-                        .as_ref()
-                        .context("No Character")?
-                        .class
-                        .as_str(),
-                ),
-            ]),
-        ];
-        let mut terminal = Terminal::new(backend)?;
-        terminal.clear()?;
-        terminal.set_cursor(0, 0)?;
+        let highlight = Style::default().add_modifier(Modifier::REVERSED);
+        let mut field_cursor_pos = None;
+        let character_text: Vec<Spans> = TEXT_FIELDS
+            .iter()
+            .map(|&field| {
+                let label = format!("{}: ", field.label());
+                let is_active = field == self.field;
+                let editing = is_active && matches!(self.input_mode, InputMode::Editing);
+                let value_style = if is_active {
+                    highlight
+                } else {
+                    Style::default()
+                };
+                if editing {
+                    // label width + char offset of the cursor in the value.
+                    field_cursor_pos =
+                        Some((label.len() + self.text_input(field).display_cursor()) as u16);
+                }
+                if editing {
+                    self.text_input(field).render_styled(label, value_style)
+                } else {
+                    Spans::from(vec![
+                        Span::styled(label, Style::default().add_modifier(Modifier::BOLD)),
+                        Span::styled(self.current_character.field(field), value_style),
+                    ])
+                }
+            })
+            .collect();
+        let field_cursor_row = TEXT_FIELDS
+            .iter()
+            .position(|&field| field == self.field)
+            .map(|i| i as u16);
+
+        let notes_active = self.field == CharacterField::Notes;
+        let notes_text: Vec<Spans> = self
+            .current_character
+            .notes
+            .rows()
+            .iter()
+            .skip(self.notes_offset)
+            .take(notes_area_height.max(1))
+            .map(|row| {
+                let end = self.notes_col_offset + notes_area_width.max(1);
+                Spans::from(Span::raw(row.render(self.notes_col_offset, end)))
+            })
+            .collect();
+
+        let prompt_line = self
+            .prompt
+            .as_ref()
+            .map(Prompt::display)
+            .or_else(|| self.status.clone());
 
         // Render the full `sheet`.
         // TODO: This also needs review, as we need to account
         // for user navigation around the sheet and how the user
         // may edit and save character data.
         terminal.draw(|f| {
-            let size = f.size();
-            let sheet = Paragraph::new(character_text).block(
+            let chunks = Self::layout(f.size());
+
+            let fields = Paragraph::new(character_text).block(
                 Block::default()
-                    .title(self.current_character.as_ref().unwrap().name.as_str())
+                    .title(self.current_character.name.as_str())
                     .borders(Borders::ALL),
             );
-            f.render_widget(sheet, size);
+            f.render_widget(fields, chunks[0]);
+
+            let notes = Paragraph::new(notes_text).block(
+                Block::default()
+                    .title("Notes")
+                    .borders(Borders::ALL)
+                    .border_style(if notes_active {
+                        Style::default().add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default()
+                    }),
+            );
+            f.render_widget(notes, chunks[1]);
+
+            if let Some(line) = &prompt_line {
+                f.render_widget(Paragraph::new(line.as_str()), chunks[2]);
+            }
         })?;
 
+        let chunks = Self::layout(terminal.size()?);
+        if let Some(prompt) = &self.prompt {
+            terminal.set_cursor(prompt.cursor as u16 + 1, chunks[2].y)?;
+            terminal.show_cursor()?;
+        } else if notes_active {
+            if let InputMode::Editing = self.input_mode {
+                let (row, column) = self.notes_cursor;
+                terminal.set_cursor(
+                    chunks[1].x + 1 + (column - self.notes_col_offset) as u16,
+                    chunks[1].y + 1 + (row - self.notes_offset) as u16,
+                )?;
+                terminal.show_cursor()?;
+            } else {
+                terminal.hide_cursor()?;
+            }
+        } else if let (Some(col), Some(row)) = (field_cursor_pos, field_cursor_row) {
+            // +1 for the block's left border, +1 for the top border.
+            terminal.set_cursor(chunks[0].x + col + 1, chunks[0].y + row + 1)?;
+            terminal.show_cursor()?;
+        } else {
+            terminal.hide_cursor()?;
+        }
+
         Ok(())
     }
 
     fn handle_keyboard_event(
-        &self,
-        stdout: &Stdout,
+        &mut self,
+        _stdout: &mut Stdout,
         event: KeyEvent,
     ) -> Result<HandleKeyboardInput> {
-        Ok(HandleKeyboardInput::Input)
+        let result = if self.prompt.is_some() {
+            self.handle_prompt_key(event)
+        } else {
+            match self.input_mode {
+                InputMode::Normal => self.handle_normal_key(event),
+                InputMode::Editing => self.handle_editing_key(event),
+            }
+        };
+        Ok(result)
     }
 }
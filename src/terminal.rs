@@ -0,0 +1,45 @@
+use anyhow::Result;
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use std::io::stdout;
+
+/// RAII guard around the terminal's raw mode and alternate screen. Entering
+/// puts the terminal into the state the TUI needs; dropping always restores
+/// it, so a panic mid-frame can't leave the user's shell corrupted.
+pub struct TerminalSession;
+
+impl TerminalSession {
+    pub fn enter() -> Result<TerminalSession> {
+        enable_raw_mode()?;
+        execute!(stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+        Ok(TerminalSession)
+    }
+
+    fn restore() {
+        // Best-effort: this also runs from the panic hook, where returning
+        // a `Result` isn't an option, so swallow errors rather than panic
+        // again while already unwinding.
+        let _ = execute!(stdout(), LeaveAlternateScreen, DisableMouseCapture);
+        let _ = disable_raw_mode();
+    }
+}
+
+impl Drop for TerminalSession {
+    fn drop(&mut self) {
+        TerminalSession::restore();
+    }
+}
+
+/// Install a panic hook that restores the terminal before printing the
+/// panic, so a panic inside `display_screen` or `handle_keyboard_event`
+/// leaves a readable backtrace instead of a wrecked terminal.
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        TerminalSession::restore();
+        default_hook(info);
+    }));
+}
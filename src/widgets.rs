@@ -0,0 +1,163 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use tui::style::{Modifier, Style};
+use tui::text::{Span, Spans};
+
+/// A single-line, single-field input, inspired by requestty's generic UI
+/// elements: owns its value and cursor, and validates/transforms each
+/// character as it's typed via `filter_map_char`.
+pub struct TextInput {
+    value: String,
+    cursor: usize,
+    filter_map_char: Box<dyn Fn(char) -> Option<char>>,
+}
+
+impl TextInput {
+    pub fn new() -> TextInput {
+        TextInput::with_filter(Some)
+    }
+
+    /// `filter_map_char` is applied to every typed character; `None` drops
+    /// it, letting a field reject or transform (e.g. upcase, digits-only)
+    /// what it accepts.
+    pub fn with_filter(filter_map_char: impl Fn(char) -> Option<char> + 'static) -> TextInput {
+        TextInput {
+            value: String::new(),
+            cursor: 0,
+            filter_map_char: Box::new(filter_map_char),
+        }
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// The cursor as a byte offset into `value()`, for slicing the string.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// The cursor as a char count into `value()`, for placing it in a
+    /// terminal column. Differs from `cursor()` as soon as `value` contains
+    /// a multi-byte character before the cursor.
+    pub fn display_cursor(&self) -> usize {
+        self.value[..self.cursor()].chars().count()
+    }
+
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.value = value.into();
+        self.cursor = self.value.len();
+    }
+
+    /// Handle a key, returning whether it was consumed.
+    pub fn handle_key(&mut self, event: KeyEvent) -> bool {
+        match event.code {
+            KeyCode::Char(c) => {
+                if let Some(c) = (self.filter_map_char)(c) {
+                    self.value.insert(self.cursor, c);
+                    self.cursor += c.len_utf8();
+                }
+                true
+            }
+            KeyCode::Backspace => {
+                if self.cursor > 0 {
+                    let prev = self.value[..self.cursor]
+                        .chars()
+                        .next_back()
+                        .expect("cursor is past the start of the value");
+                    self.cursor -= prev.len_utf8();
+                    self.value.remove(self.cursor);
+                }
+                true
+            }
+            KeyCode::Left => {
+                if self.cursor > 0 {
+                    let prev = self.value[..self.cursor]
+                        .chars()
+                        .next_back()
+                        .expect("cursor is past the start of the value");
+                    self.cursor -= prev.len_utf8();
+                }
+                true
+            }
+            KeyCode::Right => {
+                if self.cursor < self.value.len() {
+                    let next = self.value[self.cursor..]
+                        .chars()
+                        .next()
+                        .expect("cursor is before the end of the value");
+                    self.cursor += next.len_utf8();
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Render `label` (as given, no separator added) followed by the value
+    /// as a styled line, with `value_style` applied only to the value (e.g.
+    /// a highlight while focused).
+    pub fn render_styled(&self, label: impl Into<String>, value_style: Style) -> Spans<'static> {
+        Spans::from(vec![
+            Span::styled(label.into(), Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(self.value.clone(), value_style),
+        ])
+    }
+}
+
+impl Default for TextInput {
+    fn default() -> TextInput {
+        TextInput::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn press(input: &mut TextInput, code: KeyCode) {
+        input.handle_key(KeyEvent::new(code, crossterm::event::KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn typing_and_backspace_round_trip() {
+        let mut input = TextInput::new();
+        press(&mut input, KeyCode::Char('h'));
+        press(&mut input, KeyCode::Char('i'));
+        assert_eq!(input.value(), "hi");
+        press(&mut input, KeyCode::Backspace);
+        assert_eq!(input.value(), "h");
+    }
+
+    #[test]
+    fn filter_drops_rejected_characters() {
+        let mut input = TextInput::with_filter(|c| c.is_ascii_digit().then_some(c));
+        press(&mut input, KeyCode::Char('1'));
+        press(&mut input, KeyCode::Char('a'));
+        press(&mut input, KeyCode::Char('2'));
+        assert_eq!(input.value(), "12");
+    }
+
+    #[test]
+    fn display_cursor_counts_chars_not_bytes() {
+        let mut input = TextInput::new();
+        press(&mut input, KeyCode::Char('é')); // 2 bytes, 1 char
+        press(&mut input, KeyCode::Char('x'));
+        assert_eq!(input.value(), "éx");
+        assert_eq!(input.cursor(), 3, "byte offset should count both é's bytes");
+        assert_eq!(
+            input.display_cursor(),
+            2,
+            "display column should count chars"
+        );
+    }
+
+    #[test]
+    fn left_and_right_move_by_whole_chars() {
+        let mut input = TextInput::new();
+        press(&mut input, KeyCode::Char('é'));
+        press(&mut input, KeyCode::Left);
+        assert_eq!(input.cursor(), 0);
+        press(&mut input, KeyCode::Right);
+        assert_eq!(input.cursor(), 'é'.len_utf8());
+    }
+}